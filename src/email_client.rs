@@ -1,15 +1,206 @@
 use crate::domain::SubscriberEmail;
 use anyhow::Context;
+use async_trait::async_trait;
+use lettre::message::{Attachment as LettreAttachment, MultiPart};
+use lettre::message::header::ContentType;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::transport::smtp::client::{Tls, TlsParameters};
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use rand::Rng;
 use reqwest::Client;
 use resend_rs::types::CreateEmailBaseOptions;
 use resend_rs::{Config, Resend};
 use secrecy::{ExposeSecret, SecretString};
+use std::time::Duration;
+
+/// A backend capable of delivering a single email.
+///
+/// `EmailClient` is written against this trait so deployments can pick their
+/// delivery mechanism at startup — Resend's HTTP API or a plain SMTP server.
+#[async_trait]
+pub trait EmailTransport: Send + Sync {
+    async fn send(&self, from: &str, message: &EmailMessage) -> anyhow::Result<()>;
+
+    /// Deliver many messages, returning one result per message in input order
+    /// so a broadcast can report individual failures without aborting.
+    ///
+    /// The default implementation sends sequentially; backends with a native
+    /// batch endpoint (Resend) override it.
+    async fn send_batch(&self, from: &str, messages: &[EmailMessage]) -> Vec<anyhow::Result<()>> {
+        let mut results = Vec::with_capacity(messages.len());
+        for message in messages {
+            results.push(self.send(from, message).await);
+        }
+        results
+    }
+}
+
+/// Maximum number of messages Resend accepts in a single batch request.
+const BATCH_CHUNK_SIZE: usize = 100;
+
+/// A file attached to an outgoing email.
+#[derive(Clone, Debug)]
+pub struct Attachment {
+    pub filename: String,
+    pub content: Vec<u8>,
+    pub content_type: String,
+}
+
+/// A fully composed email, produced by [`EmailMessage::builder`].
+///
+/// The sender is supplied by the [`EmailClient`], so the builder only carries
+/// the recipients, envelope extras (cc/bcc/reply-to), subject, bodies and any
+/// attachments.
+#[derive(Clone, Debug, Default)]
+pub struct EmailMessage {
+    to: Vec<String>,
+    cc: Vec<String>,
+    bcc: Vec<String>,
+    reply_to: Vec<String>,
+    subject: String,
+    html: Option<String>,
+    text: Option<String>,
+    attachments: Vec<Attachment>,
+    idempotency_key: Option<String>,
+}
+
+impl EmailMessage {
+    pub fn builder() -> EmailBuilder {
+        EmailBuilder::default()
+    }
+
+    /// The idempotency key forwarded to the provider to collapse duplicate
+    /// sends on retry. Falls back to a deterministic hash of the full envelope
+    /// — recipients (to/cc/bcc), reply-to, subject, bodies and attachments —
+    /// when the caller did not supply one, so messages that differ in any of
+    /// those fields get distinct keys and are not deduped against each other.
+    pub fn idempotency_key(&self) -> String {
+        if let Some(key) = &self.idempotency_key {
+            return key.clone();
+        }
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+        let mut hasher = DefaultHasher::new();
+        self.to.hash(&mut hasher);
+        self.cc.hash(&mut hasher);
+        self.bcc.hash(&mut hasher);
+        self.reply_to.hash(&mut hasher);
+        self.subject.hash(&mut hasher);
+        self.html.hash(&mut hasher);
+        self.text.hash(&mut hasher);
+        for attachment in &self.attachments {
+            attachment.filename.hash(&mut hasher);
+            attachment.content_type.hash(&mut hasher);
+            attachment.content.hash(&mut hasher);
+        }
+        format!("{:016x}", hasher.finish())
+    }
+}
+
+/// Fluent builder for [`EmailMessage`]; call [`EmailBuilder::build`] to get a
+/// validated message.
+#[derive(Default)]
+pub struct EmailBuilder {
+    message: EmailMessage,
+}
+
+impl EmailBuilder {
+    pub fn to(mut self, address: impl Into<String>) -> Self {
+        self.message.to.push(address.into());
+        self
+    }
+
+    pub fn cc(mut self, address: impl Into<String>) -> Self {
+        self.message.cc.push(address.into());
+        self
+    }
+
+    pub fn bcc(mut self, address: impl Into<String>) -> Self {
+        self.message.bcc.push(address.into());
+        self
+    }
+
+    pub fn reply_to(mut self, address: impl Into<String>) -> Self {
+        self.message.reply_to.push(address.into());
+        self
+    }
+
+    pub fn subject(mut self, subject: impl Into<String>) -> Self {
+        self.message.subject = subject.into();
+        self
+    }
+
+    pub fn html(mut self, html: impl Into<String>) -> Self {
+        self.message.html = Some(html.into());
+        self
+    }
+
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.message.text = Some(text.into());
+        self
+    }
+
+    /// Supply an explicit idempotency key; defaults to a content hash otherwise.
+    pub fn idempotency_key(mut self, key: impl Into<String>) -> Self {
+        self.message.idempotency_key = Some(key.into());
+        self
+    }
+
+    pub fn attachment(
+        mut self,
+        filename: impl Into<String>,
+        content: Vec<u8>,
+        content_type: impl Into<String>,
+    ) -> Self {
+        self.message.attachments.push(Attachment {
+            filename: filename.into(),
+            content,
+            content_type: content_type.into(),
+        });
+        self
+    }
+
+    /// Validate and return the composed message. Fails if there is no
+    /// recipient, no subject, or no body.
+    pub fn build(self) -> anyhow::Result<EmailMessage> {
+        let message = self.message;
+        anyhow::ensure!(!message.to.is_empty(), "an email needs at least one recipient");
+        anyhow::ensure!(!message.subject.is_empty(), "an email needs a subject");
+        anyhow::ensure!(
+            message.html.is_some() || message.text.is_some(),
+            "an email needs an HTML or text body"
+        );
+        Ok(message)
+    }
+}
+
+/// Controls how transient send failures are retried.
+///
+/// Only network/timeout errors and HTTP 429/5xx responses are retried;
+/// 4xx validation errors are permanent and surface immediately.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first one.
+    pub max_attempts: u32,
+    /// Backoff base: the delay cap after attempt `n` is `base_delay * 2^n`.
+    pub base_delay: Duration,
+    /// Upper bound on any single backoff delay.
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(10),
+        }
+    }
+}
 
 pub struct EmailClient {
-    http_client: Client,
-    base_url: String,
     sender: SubscriberEmail,
-    authorization_token: SecretString,
+    transport: Box<dyn EmailTransport>,
 }
 
 impl EmailClient {
@@ -18,19 +209,31 @@ impl EmailClient {
         sender: SubscriberEmail,
         authorization_token: SecretString,
         timeout: std::time::Duration,
-    ) -> Self {
-        let http_client = Client::builder()
-            .timeout(timeout)
-            .build()
-            .unwrap();
-        Self {
-            http_client,
-            base_url,
-            sender,
-            authorization_token,
-        }
+    ) -> anyhow::Result<Self> {
+        let transport = ResendTransport::new(base_url, authorization_token, timeout)?;
+        Ok(Self::with_transport(sender, Box::new(transport)))
+    }
+
+    /// Build a client around an arbitrary [`EmailTransport`] — used to inject
+    /// an SMTP backend (or a tuned Resend transport) at startup.
+    pub fn with_transport(sender: SubscriberEmail, transport: Box<dyn EmailTransport>) -> Self {
+        Self { sender, transport }
+    }
+
+    /// Send a fully composed [`EmailMessage`] (cc/bcc/reply-to/attachments).
+    pub async fn send(&self, message: EmailMessage) -> anyhow::Result<()> {
+        self.transport.send(self.sender.as_ref(), &message).await
     }
 
+    /// Deliver a newsletter issue to many recipients at once, returning one
+    /// result per message (in input order) so callers can see which individual
+    /// sends failed without aborting the whole broadcast.
+    pub async fn send_batch(&self, messages: Vec<EmailMessage>) -> Vec<anyhow::Result<()>> {
+        self.transport.send_batch(self.sender.as_ref(), &messages).await
+    }
+
+    /// Backwards-compatible single-recipient send used by the
+    /// subscription-confirmation flow.
     pub async fn send_email(
         &self,
         recipient: SubscriberEmail,
@@ -38,39 +241,313 @@ impl EmailClient {
         html_content: &str,
         text_content: &str,
     ) -> anyhow::Result<()> {
+        let message = EmailMessage::builder()
+            .to(recipient.as_ref())
+            .subject(subject)
+            .html(html_content)
+            .text(text_content)
+            .build()?;
+        self.send(message).await
+    }
+}
+
+/// Delivers email through Resend's HTTP API. Single sends retry transient
+/// failures; batch requests are sent once, without retries.
+pub struct ResendTransport {
+    resend: Resend,
+    retry_policy: RetryPolicy,
+}
+
+impl ResendTransport {
+    pub fn new(
+        base_url: String,
+        authorization_token: SecretString,
+        timeout: std::time::Duration,
+    ) -> anyhow::Result<Self> {
+        let http_client = Client::builder()
+            .timeout(timeout)
+            .build()
+            .context("failed to build the HTTP client")?;
+
         let resend = Resend::with_config(
-            Config::builder(self.authorization_token.expose_secret())
+            Config::builder(authorization_token.expose_secret())
                 .base_url(
                     // this is Resend's default base url, but you can provide
                     // your override here, which is especially helpful when running
                     // many parallel tests and intercepting email requests
                     // in each of them
-                    self.base_url.parse().context("failed to parse URL")?,
-                )
-                .client(self.http_client.clone()
+                    base_url.parse().context("failed to parse URL")?,
                 )
+                .client(http_client)
                 .build(),
         );
 
-        let from = self.sender.as_ref();
-        let to = [recipient.as_ref()];
+        Ok(Self {
+            resend,
+            retry_policy: RetryPolicy::default(),
+        })
+    }
+
+    /// Override the retry policy used for transient send failures.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+}
+
+impl ResendTransport {
+    /// Map an [`EmailMessage`] onto Resend's `CreateEmailBaseOptions`.
+    fn options(from: &str, message: &EmailMessage) -> CreateEmailBaseOptions {
+        let to: Vec<&str> = message.to.iter().map(String::as_str).collect();
+        let mut options = CreateEmailBaseOptions::new(from, to, &message.subject);
+        if let Some(html) = &message.html {
+            options = options.with_html(html);
+        }
+        if let Some(text) = &message.text {
+            options = options.with_text(text);
+        }
+        if !message.cc.is_empty() {
+            let cc: Vec<&str> = message.cc.iter().map(String::as_str).collect();
+            options = options.with_cc(cc);
+        }
+        if !message.bcc.is_empty() {
+            let bcc: Vec<&str> = message.bcc.iter().map(String::as_str).collect();
+            options = options.with_bcc(bcc);
+        }
+        if !message.reply_to.is_empty() {
+            let reply_to: Vec<&str> = message.reply_to.iter().map(String::as_str).collect();
+            options = options.with_reply(reply_to);
+        }
+        for attachment in &message.attachments {
+            options = options.with_attachment(
+                resend_rs::types::Attachment::from_content(attachment.content.clone())
+                    .with_filename(&attachment.filename)
+                    .with_content_type(&attachment.content_type),
+            );
+        }
+        // Forwarded to Resend as the `Idempotency-Key` header so a replayed
+        // request (e.g. after a retry) is deduplicated server-side.
+        options = options.with_idempotency_key(message.idempotency_key());
+        options
+    }
+}
+
+#[async_trait]
+impl EmailTransport for ResendTransport {
+    async fn send(&self, from: &str, message: &EmailMessage) -> anyhow::Result<()> {
+        // Retry transient failures with exponential backoff and full jitter.
+        let policy = &self.retry_policy;
+        let mut attempt: u32 = 0;
+        loop {
+            let request = Self::options(from, message);
+            match self.resend.emails.send(request).await {
+                Ok(_) => return Ok(()),
+                Err(error) => {
+                    let last_attempt = attempt + 1 >= policy.max_attempts;
+                    if last_attempt || !is_transient(&error) {
+                        return Err(error.into());
+                    }
+                    sleep(backoff_delay(policy, attempt, retry_after(&error))).await;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    async fn send_batch(&self, from: &str, messages: &[EmailMessage]) -> Vec<anyhow::Result<()>> {
+        let mut results = Vec::with_capacity(messages.len());
+        for chunk in messages.chunks(BATCH_CHUNK_SIZE) {
+            let payload: Vec<CreateEmailBaseOptions> =
+                chunk.iter().map(|m| Self::options(from, m)).collect();
+            match self.resend.batch.send(payload).await {
+                // Resend returns one entry per submitted message, in order;
+                // map each back to its message so one bad recipient doesn't
+                // report the whole chunk as failed.
+                Ok(response) => {
+                    let mut sent = response.data.into_iter();
+                    for _ in chunk {
+                        match sent.next() {
+                            Some(_) => results.push(Ok(())),
+                            None => results.push(Err(anyhow::anyhow!(
+                                "batch response missing an entry for this message"
+                            ))),
+                        }
+                    }
+                }
+                // A transport-level failure sinks the whole request; the rest
+                // of the broadcast still goes out.
+                Err(error) => results.extend(
+                    chunk
+                        .iter()
+                        .map(|_| Err(anyhow::anyhow!("batch send failed: {error}"))),
+                ),
+            }
+        }
+        results
+    }
+}
+
+/// Delivers email over SMTP via `lettre`, upgrading to TLS with STARTTLS
+/// when the server advertises it and falling back to cleartext otherwise.
+pub struct SmtpTransport {
+    mailer: AsyncSmtpTransport<Tokio1Executor>,
+}
 
-        let email = CreateEmailBaseOptions::new(from, to, subject)
-            .with_text(text_content)
-            .with_html(html_content);
+impl SmtpTransport {
+    pub fn new(
+        host: &str,
+        port: u16,
+        username: String,
+        password: SecretString,
+    ) -> anyhow::Result<Self> {
+        let tls = TlsParameters::new(host.to_owned()).context("failed to build TLS parameters")?;
+        let mailer = AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(host)
+            .port(port)
+            // opportunistic STARTTLS: encrypt when the server offers it,
+            // otherwise send in cleartext
+            .tls(Tls::Opportunistic(tls))
+            .credentials(Credentials::new(
+                username,
+                password.expose_secret().to_owned(),
+            ))
+            .build();
+        Ok(Self { mailer })
+    }
+}
 
-        let _email = resend.emails.send(email).await?;
-        println!("{:?}", _email);
+#[async_trait]
+impl EmailTransport for SmtpTransport {
+    async fn send(&self, from: &str, message: &EmailMessage) -> anyhow::Result<()> {
+        let mut builder = Message::builder()
+            .from(from.parse().context("invalid sender mailbox")?)
+            .subject(&message.subject);
+        for to in &message.to {
+            builder = builder.to(to.parse().context("invalid recipient mailbox")?);
+        }
+        for cc in &message.cc {
+            builder = builder.cc(cc.parse().context("invalid cc mailbox")?);
+        }
+        for bcc in &message.bcc {
+            builder = builder.bcc(bcc.parse().context("invalid bcc mailbox")?);
+        }
+        for reply_to in &message.reply_to {
+            builder = builder.reply_to(reply_to.parse().context("invalid reply-to mailbox")?);
+        }
+
+        // Body: an alternative plain/HTML part, wrapped in a mixed part when
+        // there are attachments.
+        let body = MultiPart::alternative_plain_html(
+            message.text.clone().unwrap_or_default(),
+            message.html.clone().unwrap_or_default(),
+        );
+        let email = if message.attachments.is_empty() {
+            builder.multipart(body)
+        } else {
+            let mut mixed = MultiPart::mixed().multipart(body);
+            for attachment in &message.attachments {
+                let content_type = ContentType::parse(&attachment.content_type)
+                    .context("invalid attachment content type")?;
+                mixed = mixed.singlepart(
+                    LettreAttachment::new(attachment.filename.clone())
+                        .body(attachment.content.clone(), content_type),
+                );
+            }
+            builder.multipart(mixed)
+        }
+        .context("failed to assemble SMTP message")?;
 
+        self.mailer.send(email).await.context("SMTP send failed")?;
         Ok(())
     }
 }
 
+/// Transport selection, deserialized from `email_client.transport` in the
+/// application configuration.
+#[derive(serde::Deserialize, Clone, Debug)]
+#[serde(tag = "transport", rename_all = "snake_case")]
+pub enum TransportSettings {
+    Resend {
+        base_url: String,
+        authorization_token: SecretString,
+        timeout_milliseconds: u64,
+    },
+    Smtp {
+        host: String,
+        port: u16,
+        username: String,
+        password: SecretString,
+    },
+}
+
+impl TransportSettings {
+    /// Build the configured transport, ready to hand to [`EmailClient::with_transport`].
+    pub fn build(self) -> anyhow::Result<Box<dyn EmailTransport>> {
+        match self {
+            TransportSettings::Resend {
+                base_url,
+                authorization_token,
+                timeout_milliseconds,
+            } => {
+                let transport = ResendTransport::new(
+                    base_url,
+                    authorization_token,
+                    Duration::from_millis(timeout_milliseconds),
+                )?;
+                Ok(Box::new(transport))
+            }
+            TransportSettings::Smtp {
+                host,
+                port,
+                username,
+                password,
+            } => Ok(Box::new(SmtpTransport::new(&host, port, username, password)?)),
+        }
+    }
+}
+
+/// Whether an error is worth retrying: transport/timeout failures and
+/// HTTP 429/5xx responses. Validation (4xx) errors are permanent.
+fn is_transient(error: &resend_rs::Error) -> bool {
+    match error {
+        resend_rs::Error::Http(e) => {
+            e.is_timeout() || e.is_connect() || e.is_request()
+        }
+        resend_rs::Error::Resend(e) => {
+            e.status_code >= 500 || e.status_code == 429
+        }
+        _ => false,
+    }
+}
+
+/// Extract a `Retry-After` floor (in seconds) from a rate-limit response, if any.
+fn retry_after(error: &resend_rs::Error) -> Option<Duration> {
+    match error {
+        resend_rs::Error::Resend(e) if e.status_code == 429 => {
+            e.retry_after.map(Duration::from_secs)
+        }
+        _ => None,
+    }
+}
+
+/// Full-jitter backoff: a random duration in `[0, cap)` where
+/// `cap = min(max_delay, base_delay * 2^attempt)`, floored by `retry_after`.
+fn backoff_delay(policy: &RetryPolicy, attempt: u32, retry_after: Option<Duration>) -> Duration {
+    let exp = policy.base_delay.saturating_mul(1u32 << attempt.min(31));
+    let cap = exp.min(policy.max_delay);
+    let cap_millis = cap.as_millis().max(1) as u64;
+    let jittered = Duration::from_millis(rand::thread_rng().gen_range(0..cap_millis));
+    retry_after.map_or(jittered, |floor| jittered.max(floor))
+}
+
+async fn sleep(delay: Duration) {
+    tokio::time::sleep(delay).await;
+}
+
 #[cfg(test)]
 mod tests {
     use claims::{assert_err, assert_ok};
     use crate::domain::SubscriberEmail;
-    use crate::email_client::EmailClient;
+    use crate::email_client::{EmailClient, ResendTransport, RetryPolicy};
     use fake::faker::internet::en::SafeEmail;
     use fake::faker::lorem::en::{Paragraph, Sentence};
     use fake::Fake;
@@ -153,7 +630,9 @@ mod tests {
                         "created_at": "2023-01-01T00:00:00.000Z"
                     }))
             )
-            .expect(1)
+            // the client should retry transient 5xx failures up to the
+            // configured number of attempts before giving up
+            .expect(3)
             .mount(&mock_server)
             .await;
 
@@ -184,7 +663,8 @@ mod tests {
                         "created_at": "2023-01-01T00:00:00.000Z"
                     }))
             )
-            .expect(1)
+            // every attempt times out, so the client exhausts its retries
+            .expect(3)
             .mount(&mock_server)
             .await;
 
@@ -197,6 +677,113 @@ mod tests {
         assert_err!(outcome);
     }
 
+    #[tokio::test]
+    async fn send_forwards_an_idempotency_key_header() {
+        // Arrange
+        let mock_server = MockServer::start().await;
+        let email_client = email_client(mock_server.uri());
+
+        Mock::given(path("/emails"))
+            .and(method("POST"))
+            .and(header_exists("Idempotency-Key"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "b1946ac9-46c4-4c8e-8b8a-8e1e8c8d8f8e",
+                "from": "test@example.com",
+                "to": ["recipient@example.com"],
+                "created_at": "2023-01-01T00:00:00.000Z"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        // Act
+        let outcome = email_client
+            .send_email(email(), &subject(), &content(), &content())
+            .await;
+
+        // Assert
+        assert_ok!(outcome);
+    }
+
+    #[tokio::test]
+    async fn send_batch_chunks_requests_at_the_configured_boundary() {
+        use crate::email_client::EmailMessage;
+
+        // Arrange
+        let mock_server = MockServer::start().await;
+        let email_client = email_client(mock_server.uri());
+
+        // 150 messages should split into two batch requests: 100 + 50.
+        let messages: Vec<EmailMessage> = (0..150)
+            .map(|_| {
+                EmailMessage::builder()
+                    .to(email().as_ref())
+                    .subject(subject())
+                    .html(content())
+                    .text(content())
+                    .build()
+                    .unwrap()
+            })
+            .collect();
+
+        // Responder that records each batch's size and echoes one `data`
+        // entry per submitted message.
+        #[derive(Clone)]
+        struct BatchResponder {
+            sizes: std::sync::Arc<std::sync::Mutex<Vec<usize>>>,
+        }
+        impl wiremock::Respond for BatchResponder {
+            fn respond(&self, request: &wiremock::Request) -> ResponseTemplate {
+                let submitted: Vec<serde_json::Value> =
+                    serde_json::from_slice(&request.body).unwrap();
+                self.sizes.lock().unwrap().push(submitted.len());
+                let data: Vec<serde_json::Value> = submitted
+                    .iter()
+                    .map(|_| serde_json::json!({ "id": "b1946ac9-46c4-4c8e-8b8a-8e1e8c8d8f8e" }))
+                    .collect();
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "data": data }))
+            }
+        }
+
+        let sizes = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        Mock::given(path("/emails/batch"))
+            .and(method("POST"))
+            .respond_with(BatchResponder {
+                sizes: sizes.clone(),
+            })
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        // Act
+        let outcomes = email_client.send_batch(messages).await;
+
+        // Assert
+        assert_eq!(outcomes.len(), 150);
+        assert!(outcomes.iter().all(|r| r.is_ok()));
+        // The boundary must fall at BATCH_CHUNK_SIZE: 100 then 50.
+        assert_eq!(*sizes.lock().unwrap(), vec![100, 50]);
+    }
+
+    #[test]
+    fn email_builder_requires_recipient_subject_and_body() {
+        use crate::email_client::EmailMessage;
+
+        // Missing recipient
+        assert_err!(EmailMessage::builder().subject("hi").text("body").build());
+        // Missing subject
+        assert_err!(EmailMessage::builder().to("a@b.com").text("body").build());
+        // Missing body
+        assert_err!(EmailMessage::builder().to("a@b.com").subject("hi").build());
+        // All present
+        assert_ok!(EmailMessage::builder()
+            .to("a@b.com")
+            .cc("c@d.com")
+            .subject("hi")
+            .html("<p>body</p>")
+            .build());
+    }
+
     /// Generate a random email subject
     fn subject() -> String {
         Sentence(1..2).fake()
@@ -216,11 +803,18 @@ mod tests {
     fn email_client(base_url: String) -> EmailClient {
         let sender = SubscriberEmail::parse(SafeEmail().fake()).unwrap();
         let auth_token = format!("re_{}", uuid::Uuid::new_v4().simple());
-        EmailClient::new(
+        let transport = ResendTransport::new(
             base_url,
-            sender,
             SecretString::new(auth_token.into_boxed_str()),
             std::time::Duration::from_millis(200),
         )
+        .unwrap()
+        // keep the backoff tiny so the retry tests stay fast
+        .with_retry_policy(RetryPolicy {
+            max_attempts: 3,
+            base_delay: std::time::Duration::from_millis(1),
+            max_delay: std::time::Duration::from_millis(10),
+        });
+        EmailClient::with_transport(sender, Box::new(transport))
     }
 }